@@ -0,0 +1,176 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+mod marker {
+    use crate::markers::{A1, A1024, A128, A16, A2, A2048, A256, A32, A4, A4096, A512, A64, A8};
+
+    /// Maps a const alignment `N` to the [`crate::markers`] type with that alignment.
+    ///
+    /// Only powers of two that have a generated `A*` marker have an impl, so
+    /// `Select: Aligner<N>` doubles as the compile-time check that `N` is a supported
+    /// power of two.
+    pub trait Aligner<const N: usize> {
+        type Type: Copy;
+    }
+
+    pub struct Select;
+
+    macro_rules! aligners {
+        ($($n:literal => $name:ty),+ $(,)?) => {
+            $(
+                impl Aligner<$n> for Select {
+                    type Type = $name;
+                }
+            )+
+        };
+    }
+
+    aligners! {
+        1 => A1,
+        2 => A2,
+        4 => A4,
+        8 => A8,
+        16 => A16,
+        32 => A32,
+        64 => A64,
+        128 => A128,
+        256 => A256,
+        512 => A512,
+        1024 => A1024,
+        2048 => A2048,
+        4096 => A4096,
+    }
+}
+
+use marker::{Aligner, Select};
+
+/// Wrap an object of type T to give it an alignment of `N` bytes, where `N` is a power of two
+/// given directly as a const generic parameter rather than named through a marker type.
+///
+/// This is handy when the alignment you need has no natural primitive to name it, such as
+/// cache-line padding: `AlignAsN<[AtomicUsize; 2], 64>` avoids false sharing without hunting
+/// for a type whose `align_of` happens to be 64.
+///
+/// `N` must be a power of two with a generated aligner (1 through 4096); other values fail to
+/// compile because [`Select`] has no [`Aligner`] impl for them.
+///
+/// Example:
+/// ```
+/// extern crate alignas;
+/// use alignas::AlignAsN;
+///
+/// let buffer: AlignAsN<[u8; 4], 64> = AlignAsN::new([0u8; 4]);
+/// assert_eq!(&*buffer as *const _ as usize % 64, 0);
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AlignAsN<T: Copy, const N: usize>
+where
+    Select: Aligner<N>,
+{
+    _marker: [<Select as Aligner<N>>::Type; 0],
+    t: T,
+}
+
+impl<T: Copy, const N: usize> AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    /// Put the given T object into a location aligned to N bytes
+    #[inline]
+    pub fn new(t: T) -> Self {
+        AlignAsN { _marker: [], t }
+    }
+}
+
+impl<T: Copy, const N: usize> Deref for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.t
+    }
+}
+
+impl<T: Copy, const N: usize> DerefMut for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.t
+    }
+}
+
+impl<T: Default + Copy, const N: usize> Default for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug + Copy, const N: usize> Debug for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: Display + Copy, const N: usize> Display for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: PartialEq + Copy, const N: usize> PartialEq for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.deref().eq(rhs)
+    }
+}
+impl<T: Eq + Copy, const N: usize> Eq for AlignAsN<T, N> where Select: Aligner<N> {}
+
+impl<T: PartialOrd + Copy, const N: usize> PartialOrd for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.deref().partial_cmp(rhs)
+    }
+}
+impl<T: Ord + Copy, const N: usize> Ord for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.deref().cmp(rhs)
+    }
+}
+
+impl<T: Hash + Copy, const N: usize> Hash for AlignAsN<T, N>
+where
+    Select: Aligner<N>,
+{
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.deref().hash(h)
+    }
+}
@@ -0,0 +1,115 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// Wrap an object of type T to give it the alignment requirements of an object of type A,
+/// without requiring `T: Copy`.
+///
+/// [`AlignAs`](crate::AlignAs) is a `union`, which only accepts `Copy` payloads. `AlignedBox` is
+/// a plain struct with a zero-length `[A; 0]` field instead: a zero-sized array still carries
+/// `A`'s alignment without contributing to the struct's size, so it raises alignment the same
+/// way a union marker field does, but without forcing `T` to be `Copy`. That means types with
+/// destructors — an aligned `Vec`, a lock, any buffer with a `Drop` impl — can be wrapped, and
+/// dropping the `AlignedBox` drops `T` normally.
+///
+/// Example:
+/// ```
+/// extern crate alignas;
+/// use alignas::AlignedBox;
+/// use std::mem;
+///
+/// let boxed: AlignedBox<Vec<u8>, u64> = AlignedBox::new(vec![1, 2, 3]);
+/// assert_eq!(&*boxed as *const _ as usize % mem::align_of::<u64>(), 0);
+/// ```
+#[repr(C)]
+pub struct AlignedBox<T, A> {
+    _marker: [A; 0],
+    t: T,
+}
+
+impl<T, A> AlignedBox<T, A> {
+    /// Put the given T object into an aligned location
+    #[inline]
+    pub fn new(t: T) -> Self {
+        AlignedBox { _marker: [], t }
+    }
+
+    /// Take ownership of the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.t
+    }
+}
+
+impl<T, A> Deref for AlignedBox<T, A> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.t
+    }
+}
+
+impl<T, A> DerefMut for AlignedBox<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.t
+    }
+}
+
+impl<T: Clone, A> Clone for AlignedBox<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.t.clone())
+    }
+}
+
+impl<T: Copy, A: Copy> Copy for AlignedBox<T, A> {}
+
+impl<T: Default, A> Default for AlignedBox<T, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, A> Debug for AlignedBox<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: Display, A> Display for AlignedBox<T, A> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: PartialEq, A> PartialEq for AlignedBox<T, A> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.deref().eq(rhs)
+    }
+}
+impl<T: Eq, A> Eq for AlignedBox<T, A> {}
+
+impl<T: PartialOrd, A> PartialOrd for AlignedBox<T, A> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.deref().partial_cmp(rhs)
+    }
+}
+impl<T: Ord, A> Ord for AlignedBox<T, A> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.deref().cmp(rhs)
+    }
+}
+
+impl<T: Hash, A> Hash for AlignedBox<T, A> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.deref().hash(h)
+    }
+}
@@ -2,7 +2,24 @@
 use core::cmp::{Ord, Ordering, PartialOrd};
 use core::fmt::{Debug, Display, Formatter, Result};
 use core::hash::{Hash, Hasher};
+use core::mem;
 use core::ops::{Deref, DerefMut};
+use core::slice;
+
+mod align_as_n;
+mod aligned_box;
+mod markers;
+mod maybe_aligned;
+mod pod;
+mod unalign;
+pub use align_as_n::AlignAsN;
+pub use aligned_box::AlignedBox;
+pub use markers::{
+    Alignment, A1, A1024, A128, A16, A2, A2048, A256, A32, A4, A4096, A512, A64, A8,
+};
+pub use maybe_aligned::{Aligned, MaybeAligned, Owned};
+pub use pod::Pod;
+pub use unalign::Unalign;
 
 /// Wrap an object of type T to give it the alignment requirements of an object of type A.
 ///
@@ -12,17 +29,15 @@ use core::ops::{Deref, DerefMut};
 /// Example:
 /// ```
 /// extern crate alignas;
-/// use alignas::AlignAs;
-/// use std::{mem, slice};
+/// use alignas::{AlignAs, A64, A8};
 ///
 /// // put some byte data into the buffer
-/// let mut buffer: AlignAs<_, u64> = AlignAs::new([0u8; 64]);
+/// let mut buffer: AlignAs<_, A64> = AlignAs::new([0u8; 64]);
 /// buffer[3..18].copy_from_slice(b"some input here");
 ///
 /// // now do something with it that requires aligned access...
-/// let mut ints = [0u64; 8];
-/// let ptr = &buffer as *const _ as *const u64;
-/// ints.copy_from_slice(unsafe { slice::from_raw_parts(ptr, 8) });
+/// let ints = AlignAs::<[u64; 8], A8>::from_byte_slice(buffer.as_bytes()).unwrap();
+/// assert_eq!(ints.len(), 8);
 /// ```
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -35,7 +50,86 @@ impl<T: Copy, A: Copy> AlignAs<T, A> {
     /// Put the given T object into an aligned location
     #[inline]
     pub fn new(t: T) -> Self {
-        AlignAs { t }
+        // Zero the whole union first so that every byte up to `size_of::<Self>()` is
+        // initialized, even when `A` is larger than `T` and leaves trailing bytes past `t`.
+        // Zeroing (rather than reading) a union never requires the zero pattern to be a valid
+        // `A` or `T`, since a union itself has no validity invariant beyond its bytes being
+        // initialized.
+        let mut this: Self = unsafe { mem::MaybeUninit::zeroed().assume_init() };
+        this.t = t;
+        this
+    }
+}
+
+impl<T: Pod, A: Copy> AlignAs<T, A> {
+    /// View the wrapper as a byte slice of length `size_of::<Self>()`.
+    ///
+    /// This covers the whole wrapper, not just `T`: if `A` requires more bytes than `T` for
+    /// alignment padding, those trailing bytes (always zeroed by [`new`](Self::new)) are
+    /// included too, so this round-trips with [`from_byte_slice`](Self::from_byte_slice).
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self as *const _ as *const u8, mem::size_of::<Self>()) }
+    }
+
+    /// View the wrapper as a mutable byte slice of length `size_of::<Self>()`.
+    ///
+    /// See [`as_bytes`](Self::as_bytes) for why this covers the whole wrapper rather than just
+    /// `T`.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self as *mut _ as *mut u8, mem::size_of::<Self>()) }
+    }
+
+    /// Reinterpret a byte slice as an `&AlignAs<T, A>`, checking that its length and address
+    /// meet the wrapper's size and alignment requirements.
+    #[inline]
+    pub fn from_byte_slice(bytes: &[u8]) -> core::result::Result<&Self, FromByteSliceError> {
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(FromByteSliceError::WrongLength {
+                expected: mem::size_of::<Self>(),
+                actual: bytes.len(),
+            });
+        }
+        if !(bytes.as_ptr() as usize).is_multiple_of(mem::align_of::<Self>()) {
+            return Err(FromByteSliceError::Misaligned {
+                required: mem::align_of::<Self>(),
+            });
+        }
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+}
+
+/// An error returned by [`AlignAs::from_byte_slice`] and [`MaybeAligned::new`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromByteSliceError {
+    /// The slice's length did not match the size of the type it was to hold.
+    WrongLength {
+        /// The expected length: `size_of::<AlignAs<T, A>>()` from
+        /// [`AlignAs::from_byte_slice`], `size_of::<T>()` from [`MaybeAligned::new`].
+        expected: usize,
+        /// The length of the slice that was passed in.
+        actual: usize,
+    },
+    /// The slice's address did not satisfy the wrapper's alignment.
+    Misaligned {
+        /// The required alignment, `align_of::<AlignAs<T, A>>()`.
+        required: usize,
+    },
+}
+
+impl Display for FromByteSliceError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            FromByteSliceError::WrongLength { expected, actual } => write!(
+                f,
+                "byte slice has wrong length: expected {}, got {}",
+                expected, actual
+            ),
+            FromByteSliceError::Misaligned { required } => {
+                write!(f, "byte slice is not aligned to {} bytes", required)
+            }
+        }
     }
 }
 
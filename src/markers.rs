@@ -0,0 +1,49 @@
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A zero-sized, `Copy` marker type with a known compile-time alignment.
+///
+/// This is implemented only by the `A1`..`A4096` types in this module (the trait is sealed), so
+/// generic code that is parameterized over an [`Alignment`] can query the requested alignment
+/// through [`Alignment::ALIGN`] without needing to name a concrete marker.
+pub trait Alignment: sealed::Sealed + Copy {
+    /// The alignment, in bytes, that this marker type requires.
+    const ALIGN: usize;
+}
+
+macro_rules! alignments {
+    ($($n:literal => $name:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!(
+                "A zero-sized marker type with alignment ", stringify!($n),
+                ", for use as the `A` parameter of `AlignAs`/`AlignedBox`.",
+            )]
+            #[repr(align($n))]
+            #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+
+            impl Alignment for $name {
+                const ALIGN: usize = $n;
+            }
+        )+
+    };
+}
+
+alignments! {
+    1 => A1,
+    2 => A2,
+    4 => A4,
+    8 => A8,
+    16 => A16,
+    32 => A32,
+    64 => A64,
+    128 => A128,
+    256 => A256,
+    512 => A512,
+    1024 => A1024,
+    2048 => A2048,
+    4096 => A4096,
+}
@@ -0,0 +1,131 @@
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+use crate::Pod;
+
+/// A guard around a foreign `&mut [u8]` buffer that may or may not already be aligned for `T`.
+///
+/// This is for interop with callers who hand you a byte buffer of unknown alignment: call
+/// [`try_into_aligned`](Self::try_into_aligned) for a zero-copy [`Aligned`] view when the buffer
+/// happens to already be aligned, or fall back to [`into_owned`](Self::into_owned), which always
+/// succeeds by copying into a properly-aligned [`Owned`] value that writes its bytes back into
+/// the original buffer when dropped.
+///
+/// Example:
+/// ```
+/// extern crate alignas;
+/// use alignas::MaybeAligned;
+///
+/// fn bump(bytes: &mut [u8]) {
+///     match MaybeAligned::<u32>::new(bytes).unwrap().try_into_aligned() {
+///         Ok(mut aligned) => *aligned += 1,
+///         Err(guard) => *guard.into_owned() += 1,
+///     }
+/// }
+/// ```
+pub struct MaybeAligned<'a, T: Pod> {
+    bytes: &'a mut [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Pod> MaybeAligned<'a, T> {
+    /// Wrap a byte buffer that holds a `T`.
+    ///
+    /// Returns [`FromByteSliceError::WrongLength`](crate::FromByteSliceError::WrongLength),
+    /// rather than panicking, if `bytes.len()` is not `size_of::<T>()`. A misaligned buffer is
+    /// not an error here.
+    #[inline]
+    pub fn new(bytes: &'a mut [u8]) -> core::result::Result<Self, crate::FromByteSliceError> {
+        if bytes.len() != mem::size_of::<T>() {
+            return Err(crate::FromByteSliceError::WrongLength {
+                expected: mem::size_of::<T>(),
+                actual: bytes.len(),
+            });
+        }
+        Ok(MaybeAligned {
+            bytes,
+            _marker: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn is_aligned(&self) -> bool {
+        (self.bytes.as_ptr() as usize).is_multiple_of(mem::align_of::<T>())
+    }
+
+    /// Borrow the buffer directly as an `&mut T` if its address already satisfies
+    /// `align_of::<T>()`, otherwise hand the guard back unchanged.
+    #[inline]
+    pub fn try_into_aligned(self) -> core::result::Result<Aligned<'a, T>, Self> {
+        if self.is_aligned() {
+            let value = unsafe { &mut *(self.bytes.as_mut_ptr() as *mut T) };
+            Ok(Aligned { value })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Copy the buffer's bytes out into a properly-aligned owned `T`. Edits made through the
+    /// returned guard are written back into the original buffer when it is dropped.
+    #[inline]
+    pub fn into_owned(self) -> Owned<'a, T> {
+        let value = unsafe { ptr::read_unaligned(self.bytes.as_ptr() as *const T) };
+        Owned {
+            value,
+            bytes: self.bytes,
+        }
+    }
+}
+
+/// A zero-copy borrow of a foreign buffer that was already aligned for `T`.
+pub struct Aligned<'a, T> {
+    value: &'a mut T,
+}
+
+impl<'a, T> Deref for Aligned<'a, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'a, T> DerefMut for Aligned<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+/// An aligned copy of a foreign buffer's bytes, written back to the buffer on drop.
+pub struct Owned<'a, T: Pod> {
+    value: T,
+    bytes: &'a mut [u8],
+}
+
+impl<'a, T: Pod> Deref for Owned<'a, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'a, T: Pod> DerefMut for Owned<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<'a, T: Pod> Drop for Owned<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        let src = &self.value as *const T as *const u8;
+        unsafe {
+            ptr::copy_nonoverlapping(src, self.bytes.as_mut_ptr(), mem::size_of::<T>());
+        }
+    }
+}
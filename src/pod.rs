@@ -0,0 +1,23 @@
+/// A plain-old-data type: one with no padding bytes, where every `size_of::<Self>()`-byte
+/// sequence is a valid instance.
+///
+/// This is what lets [`AlignAs`](crate::AlignAs) safely expose `T` as a byte slice and
+/// reconstruct it from one — without this bound, a `T` with padding (e.g. a tuple of
+/// differently-sized fields) would expose uninitialized bytes through `as_bytes`, and a `T`
+/// with invalid bit patterns (e.g. `bool`) could be reconstructed from arbitrary bytes.
+///
+/// # Safety
+///
+/// Implementors must guarantee `Self` has no padding bytes and that every bit pattern of
+/// `size_of::<Self>()` bytes is a valid `Self`.
+pub unsafe trait Pod: Copy {}
+
+macro_rules! impl_pod {
+    ($($t:ty),+ $(,)?) => {
+        $(unsafe impl Pod for $t {})+
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
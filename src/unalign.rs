@@ -0,0 +1,167 @@
+use core::cmp::{Ord, Ordering, PartialOrd};
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::hash::{Hash, Hasher};
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+/// Wrap an object of type T to strip its alignment requirement down to 1.
+///
+/// This is the inverse of [`AlignAs`](crate::AlignAs): instead of raising a type's alignment,
+/// `Unalign<T>` lets you place a `T` at an address that might not satisfy `align_of::<T>()`,
+/// which is the common situation when reading structured data out of an arbitrary byte buffer.
+/// Because the inner `T` may genuinely be misaligned, `Unalign` does not implement `Deref`;
+/// instead it offers explicit access patterns that are sound regardless of alignment.
+///
+/// Example:
+/// ```
+/// extern crate alignas;
+/// use alignas::Unalign;
+/// use std::ptr;
+///
+/// let bytes = [1u8, 0, 0, 0, 2, 0, 0, 0];
+/// // `bytes[1..5]` is not 4-byte aligned in general, so read it through `Unalign`.
+/// let wrapped: Unalign<u32> =
+///     unsafe { ptr::read_unaligned(bytes[1..5].as_ptr() as *const Unalign<u32>) };
+/// assert_eq!(wrapped.get(), u32::from_ne_bytes([0, 0, 0, 2]));
+/// ```
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct Unalign<T>(T);
+
+impl<T> Unalign<T> {
+    /// Wrap `t`, stripping its alignment requirement.
+    #[inline]
+    pub fn new(t: T) -> Self {
+        Unalign(t)
+    }
+
+    /// Copy the wrapped value out by value.
+    #[inline]
+    pub fn get(self) -> T
+    where
+        T: Copy,
+    {
+        unsafe { ptr::read_unaligned(ptr::addr_of!(self.0)) }
+    }
+
+    /// Take ownership of the wrapped value, consuming `self`.
+    ///
+    /// Unlike [`get`](Self::get), this does not require `T: Copy`: the value is read out with
+    /// `read_unaligned` and `self` is forgotten so its destructor does not also run on the bits
+    /// that were just moved out.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        unsafe { ptr::read_unaligned(ptr::addr_of!(this.0)) }
+    }
+
+    /// Return a reference to the wrapped value, if its address happens to satisfy
+    /// `align_of::<T>()`.
+    #[inline]
+    pub fn try_deref(&self) -> Option<&T> {
+        let ptr = ptr::addr_of!(self.0);
+        if (ptr as usize).is_multiple_of(mem::align_of::<T>()) {
+            Some(unsafe { &*ptr })
+        } else {
+            None
+        }
+    }
+
+    /// Return a mutable reference to the wrapped value, if its address happens to satisfy
+    /// `align_of::<T>()`.
+    #[inline]
+    pub fn try_deref_mut(&mut self) -> Option<&mut T> {
+        let ptr = ptr::addr_of_mut!(self.0);
+        if (ptr as usize).is_multiple_of(mem::align_of::<T>()) {
+            Some(unsafe { &mut *ptr })
+        } else {
+            None
+        }
+    }
+
+    /// Read the wrapped value into an aligned stack temporary, run `f` on it, and write the
+    /// (possibly modified) result back.
+    ///
+    /// Requires `T: Copy` so that if `f` panics, unwinding simply drops the stack temporary
+    /// without double-dropping the bits still sitting in `self`.
+    #[inline]
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        T: Copy,
+    {
+        let mut tmp = unsafe { ptr::read_unaligned(ptr::addr_of!(self.0)) };
+        let r = f(&mut tmp);
+        unsafe { ptr::write_unaligned(ptr::addr_of_mut!(self.0), tmp) };
+        r
+    }
+
+    /// Return a reference to the wrapped value without checking alignment.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `self` is located at an address satisfying
+    /// `align_of::<T>()`.
+    #[inline]
+    pub unsafe fn deref_unchecked(&self) -> &T {
+        &*ptr::addr_of!(self.0)
+    }
+
+    /// Return a mutable reference to the wrapped value without checking alignment.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `self` is located at an address satisfying
+    /// `align_of::<T>()`.
+    #[inline]
+    pub unsafe fn deref_mut_unchecked(&mut self) -> &mut T {
+        &mut *ptr::addr_of_mut!(self.0)
+    }
+}
+
+impl<T: Default> Default for Unalign<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug + Copy> Debug for Unalign<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<T: Display + Copy> Display for Unalign<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<T: PartialEq + Copy> PartialEq for Unalign<T> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.get().eq(&rhs.get())
+    }
+}
+impl<T: Eq + Copy> Eq for Unalign<T> {}
+
+impl<T: PartialOrd + Copy> PartialOrd for Unalign<T> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.get().partial_cmp(&rhs.get())
+    }
+}
+impl<T: Ord + Copy> Ord for Unalign<T> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.get().cmp(&rhs.get())
+    }
+}
+
+impl<T: Hash + Copy> Hash for Unalign<T> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.get().hash(h)
+    }
+}